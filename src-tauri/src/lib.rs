@@ -9,6 +9,10 @@ use regex::Regex;
 use winreg::enums::*;
 #[cfg(target_os = "windows")]
 use winreg::RegKey;
+#[cfg(target_os = "windows")]
+use mslnk::ShellLink;
+#[cfg(target_os = "linux")]
+use dbus::blocking::Connection;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Game {
@@ -16,6 +20,7 @@ struct Game {
     app_id: String,
     path: String,
     status: String,
+    install_progress: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -28,6 +33,438 @@ struct ShortcutFix {
     error: Option<String>,
 }
 
+/// Structured progress event emitted by `quick_fix_shortcuts` over its `Channel`
+/// so the UI can render a progress bar and a scrolling log without blocking.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ShortcutProgress {
+    label: Option<String>,
+    progress: f32,
+    log_line: String,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct NonSteamShortcut {
+    steamid: String,
+    app_id: u32,
+    appname: String,
+    exe: String,
+    start_dir: String,
+    icon: String,
+    shortcut_path: String,
+    launch_options: String,
+    is_hidden: bool,
+    allow_desktop_config: bool,
+    allow_overlay: bool,
+    open_vr: bool,
+    devkit: bool,
+    devkit_game_id: String,
+    last_play_time: i32,
+    tags: Vec<String>,
+}
+
+impl Default for NonSteamShortcut {
+    fn default() -> Self {
+        NonSteamShortcut {
+            steamid: String::new(),
+            app_id: 0,
+            appname: String::new(),
+            exe: String::new(),
+            start_dir: String::new(),
+            icon: String::new(),
+            shortcut_path: String::new(),
+            launch_options: String::new(),
+            is_hidden: false,
+            allow_desktop_config: true,
+            allow_overlay: true,
+            open_vr: false,
+            devkit: false,
+            devkit_game_id: String::new(),
+            last_play_time: 0,
+            tags: Vec::new(),
+        }
+    }
+}
+
+// Minimal reader/writer for Steam's binary VDF format used by shortcuts.vdf.
+// Tags: 0x00 object, 0x01 string field, 0x02 int32 field, 0x08 end of object.
+mod binary_vdf {
+    use std::collections::BTreeMap;
+
+    const TAG_OBJECT: u8 = 0x00;
+    const TAG_STRING: u8 = 0x01;
+    const TAG_INT: u8 = 0x02;
+    const TAG_OBJECT_END: u8 = 0x08;
+
+    #[derive(Debug, Clone)]
+    pub enum VdfValue {
+        Object(Vec<(String, VdfValue)>),
+        Str(String),
+        Int(i32),
+    }
+
+    impl VdfValue {
+        pub fn as_object(&self) -> Option<&Vec<(String, VdfValue)>> {
+            match self {
+                VdfValue::Object(entries) => Some(entries),
+                _ => None,
+            }
+        }
+
+        pub fn get(&self, key: &str) -> Option<&VdfValue> {
+            self.as_object()?.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        }
+
+        pub fn as_str(&self) -> &str {
+            match self {
+                VdfValue::Str(s) => s,
+                _ => "",
+            }
+        }
+
+        pub fn as_int(&self) -> i32 {
+            match self {
+                VdfValue::Int(i) => *i,
+                _ => 0,
+            }
+        }
+    }
+
+    struct Reader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Reader<'a> {
+        fn read_u8(&mut self) -> Option<u8> {
+            let byte = *self.data.get(self.pos)?;
+            self.pos += 1;
+            Some(byte)
+        }
+
+        fn read_cstring(&mut self) -> Option<String> {
+            let start = self.pos;
+            while *self.data.get(self.pos)? != 0 {
+                self.pos += 1;
+            }
+            let bytes = &self.data[start..self.pos];
+            self.pos += 1; // consume the terminating null
+            Some(String::from_utf8_lossy(bytes).into_owned())
+        }
+
+        fn read_i32(&mut self) -> Option<i32> {
+            let bytes = self.data.get(self.pos..self.pos + 4)?;
+            self.pos += 4;
+            Some(i32::from_le_bytes(bytes.try_into().ok()?))
+        }
+
+        fn read_object(&mut self) -> Option<Vec<(String, VdfValue)>> {
+            let mut entries = Vec::new();
+            loop {
+                match self.read_u8()? {
+                    TAG_OBJECT_END => break,
+                    TAG_OBJECT => {
+                        let key = self.read_cstring()?;
+                        let value = VdfValue::Object(self.read_object()?);
+                        entries.push((key, value));
+                    }
+                    TAG_STRING => {
+                        let key = self.read_cstring()?;
+                        let value = self.read_cstring()?;
+                        entries.push((key, VdfValue::Str(value)));
+                    }
+                    TAG_INT => {
+                        let key = self.read_cstring()?;
+                        let value = self.read_i32()?;
+                        entries.push((key, VdfValue::Int(value)));
+                    }
+                    _ => return None,
+                }
+            }
+            Some(entries)
+        }
+    }
+
+    /// Parses a `shortcuts.vdf` file and returns the `shortcuts` root object's children.
+    pub fn parse(data: &[u8]) -> Result<BTreeMap<String, VdfValue>, String> {
+        let mut reader = Reader { data, pos: 0 };
+
+        if reader.read_u8() != Some(TAG_OBJECT) {
+            return Err("Not a valid shortcuts.vdf (missing root object)".to_string());
+        }
+        let root_key = reader.read_cstring().ok_or("Truncated root key")?;
+        if root_key != "shortcuts" {
+            return Err(format!("Unexpected root key \"{}\"", root_key));
+        }
+        let entries = reader.read_object().ok_or("Truncated shortcuts.vdf")?;
+        Ok(entries.into_iter().collect())
+    }
+
+    fn write_cstring(buf: &mut Vec<u8>, s: &str) {
+        buf.extend_from_slice(s.as_bytes());
+        buf.push(0);
+    }
+
+    fn write_value(buf: &mut Vec<u8>, key: &str, value: &VdfValue) {
+        match value {
+            VdfValue::Object(entries) => {
+                buf.push(TAG_OBJECT);
+                write_cstring(buf, key);
+                for (child_key, child_value) in entries {
+                    write_value(buf, child_key, child_value);
+                }
+                buf.push(TAG_OBJECT_END);
+            }
+            VdfValue::Str(s) => {
+                buf.push(TAG_STRING);
+                write_cstring(buf, key);
+                write_cstring(buf, s);
+            }
+            VdfValue::Int(i) => {
+                buf.push(TAG_INT);
+                write_cstring(buf, key);
+                buf.extend_from_slice(&i.to_le_bytes());
+            }
+        }
+    }
+
+    /// Serializes the `shortcuts` entries back into a `shortcuts.vdf` byte stream.
+    pub fn write(entries: &BTreeMap<String, VdfValue>) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.push(TAG_OBJECT);
+        write_cstring(&mut buf, "shortcuts");
+
+        // Entries are keyed by numeric index ("0", "1", ... "10", ...), which
+        // sort lexicographically in a BTreeMap ("10" before "2"). Steam's own
+        // writer always emits them in ascending numeric order, so sort by the
+        // parsed index rather than the map's natural string order.
+        let mut ordered: Vec<(&String, &VdfValue)> = entries.iter().collect();
+        ordered.sort_by_key(|(key, _)| key.parse::<usize>().unwrap_or(usize::MAX));
+
+        for (key, value) in ordered {
+            write_value(&mut buf, key, value);
+        }
+        buf.push(TAG_OBJECT_END); // close "shortcuts"
+        buf.push(TAG_OBJECT_END); // close root
+        buf
+    }
+}
+
+use binary_vdf::VdfValue;
+
+// CRC32 (IEEE 802.3) used to derive Steam's non-Steam-game app id from Exe + AppName.
+fn crc32(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB88320;
+    let mut crc = 0xFFFFFFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            if crc & 1 != 0 {
+                crc = (crc >> 1) ^ POLY;
+            } else {
+                crc >>= 1;
+            }
+        }
+    }
+    !crc
+}
+
+fn nonsteam_app_id(exe: &str, appname: &str) -> u32 {
+    let mut input = String::with_capacity(exe.len() + appname.len());
+    input.push_str(exe);
+    input.push_str(appname);
+    crc32(input.as_bytes()) | 0x80000000
+}
+
+/// Returns every `userdata/<steamid>/config/shortcuts.vdf` path on the
+/// machine, paired with its account's `steamid` folder name. Several Steam
+/// accounts commonly share one machine, so callers must pick by `steamid`
+/// rather than assume there is only one.
+fn find_shortcuts_vdf_paths(steam_path: &Path) -> Vec<(String, PathBuf)> {
+    let userdata = steam_path.join("userdata");
+    let mut paths = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(&userdata) {
+        for entry in entries.flatten() {
+            let steamid = entry.file_name().to_string_lossy().into_owned();
+            let config_dir = entry.path().join("config");
+            if config_dir.is_dir() {
+                paths.push((steamid, config_dir.join("shortcuts.vdf")));
+            }
+        }
+    }
+
+    paths
+}
+
+fn shortcuts_vdf_path_for(steam_path: &Path, steamid: &str) -> Result<PathBuf, String> {
+    find_shortcuts_vdf_paths(steam_path)
+        .into_iter()
+        .find(|(id, _)| id == steamid)
+        .map(|(_, path)| path)
+        .ok_or_else(|| format!("No userdata/{}/config directory found", steamid))
+}
+
+fn entry_to_shortcut(steamid: &str, entry: &VdfValue) -> NonSteamShortcut {
+    let exe = entry.get("Exe").map(|v| v.as_str().to_string()).unwrap_or_default();
+    let appname = entry.get("appname").map(|v| v.as_str().to_string()).unwrap_or_default();
+    let tags = entry
+        .get("tags")
+        .and_then(|v| v.as_object())
+        .map(|entries| entries.iter().map(|(_, v)| v.as_str().to_string()).collect())
+        .unwrap_or_default();
+
+    NonSteamShortcut {
+        steamid: steamid.to_string(),
+        app_id: nonsteam_app_id(&exe, &appname),
+        appname,
+        exe,
+        start_dir: entry.get("StartDir").map(|v| v.as_str().to_string()).unwrap_or_default(),
+        icon: entry.get("icon").map(|v| v.as_str().to_string()).unwrap_or_default(),
+        shortcut_path: entry.get("ShortcutPath").map(|v| v.as_str().to_string()).unwrap_or_default(),
+        launch_options: entry.get("LaunchOptions").map(|v| v.as_str().to_string()).unwrap_or_default(),
+        is_hidden: entry.get("IsHidden").map(|v| v.as_int() != 0).unwrap_or(false),
+        allow_desktop_config: entry.get("AllowDesktopConfig").map(|v| v.as_int() != 0).unwrap_or(true),
+        allow_overlay: entry.get("AllowOverlay").map(|v| v.as_int() != 0).unwrap_or(true),
+        open_vr: entry.get("OpenVR").map(|v| v.as_int() != 0).unwrap_or(false),
+        devkit: entry.get("Devkit").map(|v| v.as_int() != 0).unwrap_or(false),
+        devkit_game_id: entry.get("DevkitGameID").map(|v| v.as_str().to_string()).unwrap_or_default(),
+        last_play_time: entry.get("LastPlayTime").map(|v| v.as_int()).unwrap_or(0),
+        tags,
+    }
+}
+
+fn shortcut_to_entry(shortcut: &NonSteamShortcut) -> VdfValue {
+    let tags = shortcut
+        .tags
+        .iter()
+        .enumerate()
+        .map(|(i, tag)| (i.to_string(), VdfValue::Str(tag.clone())))
+        .collect();
+
+    VdfValue::Object(vec![
+        ("appname".to_string(), VdfValue::Str(shortcut.appname.clone())),
+        ("Exe".to_string(), VdfValue::Str(shortcut.exe.clone())),
+        ("StartDir".to_string(), VdfValue::Str(shortcut.start_dir.clone())),
+        ("icon".to_string(), VdfValue::Str(shortcut.icon.clone())),
+        ("ShortcutPath".to_string(), VdfValue::Str(shortcut.shortcut_path.clone())),
+        ("LaunchOptions".to_string(), VdfValue::Str(shortcut.launch_options.clone())),
+        ("IsHidden".to_string(), VdfValue::Int(shortcut.is_hidden as i32)),
+        ("AllowDesktopConfig".to_string(), VdfValue::Int(shortcut.allow_desktop_config as i32)),
+        ("AllowOverlay".to_string(), VdfValue::Int(shortcut.allow_overlay as i32)),
+        ("OpenVR".to_string(), VdfValue::Int(shortcut.open_vr as i32)),
+        ("Devkit".to_string(), VdfValue::Int(shortcut.devkit as i32)),
+        ("DevkitGameID".to_string(), VdfValue::Str(shortcut.devkit_game_id.clone())),
+        ("LastPlayTime".to_string(), VdfValue::Int(shortcut.last_play_time)),
+        ("tags".to_string(), VdfValue::Object(tags)),
+    ])
+}
+
+fn read_shortcuts_file(path: &Path) -> Result<std::collections::BTreeMap<String, VdfValue>, String> {
+    if !path.exists() {
+        return Ok(std::collections::BTreeMap::new());
+    }
+    let data = fs::read(path).map_err(|e| format!("Failed to read shortcuts.vdf: {}", e))?;
+    binary_vdf::parse(&data)
+}
+
+/// Entries are keyed by numeric index ("0", "1", ... "10", ...), which sort
+/// lexicographically in a `BTreeMap` ("10" before "2"). Anywhere entries are
+/// read back for display or renumbering must sort by the parsed index
+/// instead, matching the order `binary_vdf::write` serializes them in.
+fn sorted_by_index(entries: &std::collections::BTreeMap<String, VdfValue>) -> Vec<(&String, &VdfValue)> {
+    let mut ordered: Vec<(&String, &VdfValue)> = entries.iter().collect();
+    ordered.sort_by_key(|(key, _)| key.parse::<usize>().unwrap_or(usize::MAX));
+    ordered
+}
+
+fn write_shortcuts_file(path: &Path, entries: &std::collections::BTreeMap<String, VdfValue>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    fs::write(path, binary_vdf::write(entries)).map_err(|e| format!("Failed to write shortcuts.vdf: {}", e))
+}
+
+/// Lists the `steamid` folder names under `userdata` so the caller can let
+/// the user pick an account before adding/listing/removing non-Steam shortcuts.
+#[tauri::command]
+fn list_steam_accounts(steam_path: String) -> Vec<String> {
+    find_shortcuts_vdf_paths(Path::new(&steam_path))
+        .into_iter()
+        .map(|(steamid, _)| steamid)
+        .collect()
+}
+
+#[tauri::command]
+fn add_nonsteam_shortcut(
+    steam_path: String,
+    steamid: String,
+    appname: String,
+    exe: String,
+    start_dir: String,
+    icon: String,
+    launch_options: String,
+) -> Result<u32, String> {
+    let shortcuts_path = shortcuts_vdf_path_for(Path::new(&steam_path), &steamid)?;
+
+    let mut entries = read_shortcuts_file(&shortcuts_path)?;
+
+    let app_id = nonsteam_app_id(&exe, &appname);
+    let shortcut = NonSteamShortcut {
+        steamid,
+        app_id,
+        appname,
+        exe,
+        start_dir,
+        icon,
+        shortcut_path: String::new(),
+        launch_options,
+        ..NonSteamShortcut::default()
+    };
+
+    let next_index = entries.len().to_string();
+    entries.insert(next_index, shortcut_to_entry(&shortcut));
+
+    write_shortcuts_file(&shortcuts_path, &entries)?;
+
+    Ok(app_id)
+}
+
+#[tauri::command]
+fn list_nonsteam_shortcuts(steam_path: String, steamid: String) -> Result<Vec<NonSteamShortcut>, String> {
+    let shortcuts_path = shortcuts_vdf_path_for(Path::new(&steam_path), &steamid)?;
+    let entries = read_shortcuts_file(&shortcuts_path)?;
+    Ok(sorted_by_index(&entries)
+        .into_iter()
+        .map(|(_, entry)| entry_to_shortcut(&steamid, entry))
+        .collect())
+}
+
+#[tauri::command]
+fn remove_nonsteam_shortcut(steam_path: String, steamid: String, app_id: u32) -> Result<(), String> {
+    let shortcuts_path = shortcuts_vdf_path_for(Path::new(&steam_path), &steamid)?;
+
+    let entries = read_shortcuts_file(&shortcuts_path)?;
+
+    let remaining: Vec<VdfValue> = sorted_by_index(&entries)
+        .into_iter()
+        .filter(|(_, entry)| entry_to_shortcut(&steamid, entry).app_id != app_id)
+        .map(|(_, entry)| entry.clone())
+        .collect();
+
+    if remaining.len() == entries.len() {
+        return Err("No non-Steam shortcut with that app id was found".to_string());
+    }
+
+    let renumbered = remaining
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| (i.to_string(), entry))
+        .collect();
+
+    write_shortcuts_file(&shortcuts_path, &renumbered)
+}
+
 fn get_steam_library_folders(steamapps_path: &str) -> Vec<PathBuf> {
     let mut libraries = vec![PathBuf::from(steamapps_path)];
     
@@ -60,8 +497,29 @@ fn extract_value(line: &str) -> Option<String> {
     }
 }
 
+/// Env var that lets power users point at a nonstandard Steam install.
+const STEAM_APP_DIR_ENV: &str = "STEAM_APP_DIR";
+
+fn home_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var("USERPROFILE").ok().map(PathBuf::from)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        std::env::var("HOME").ok().map(PathBuf::from)
+    }
+}
+
 #[cfg(target_os = "windows")]
 fn find_steam_install_directory() -> Result<PathBuf, String> {
+    if let Ok(override_path) = std::env::var(STEAM_APP_DIR_ENV) {
+        let path = PathBuf::from(override_path);
+        if path.join("steam.exe").exists() {
+            return Ok(path);
+        }
+    }
+
     // Try default location first
     let default_path = PathBuf::from("C:\\Program Files (x86)\\Steam");
     if default_path.join("steam.exe").exists() {
@@ -82,21 +540,62 @@ fn find_steam_install_directory() -> Result<PathBuf, String> {
     Err("Could not find Steam installation directory".to_string())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(target_os = "macos")]
+fn find_steam_install_directory() -> Result<PathBuf, String> {
+    if let Ok(override_path) = std::env::var(STEAM_APP_DIR_ENV) {
+        let path = PathBuf::from(override_path);
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let home = home_dir().ok_or("Could not determine home directory")?;
+    let default_path = home.join("Library/Application Support/Steam");
+    if default_path.exists() {
+        return Ok(default_path);
+    }
+
+    Err("Could not find Steam installation directory".to_string())
+}
+
+#[cfg(target_os = "linux")]
 fn find_steam_install_directory() -> Result<PathBuf, String> {
-    Err("Quick fix only supported on Windows".to_string())
+    if let Ok(override_path) = std::env::var(STEAM_APP_DIR_ENV) {
+        let path = PathBuf::from(override_path);
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let home = home_dir().ok_or("Could not determine home directory")?;
+    let candidates = [
+        home.join(".steam/steam"),
+        home.join(".local/share/Steam"),
+        home.join(".var/app/com.valvesoftware.Steam/data/Steam"),
+    ];
+
+    candidates
+        .into_iter()
+        .find(|path| path.exists())
+        .ok_or_else(|| "Could not find Steam installation directory".to_string())
 }
 
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn find_steam_install_directory() -> Result<PathBuf, String> {
+    Err("Steam installation discovery is not supported on this platform".to_string())
+}
+
+#[cfg(target_os = "windows")]
 fn get_shortcut_locations() -> Vec<PathBuf> {
     let mut locations = Vec::new();
 
-    if let Ok(userprofile) = std::env::var("USERPROFILE") {
+    if let Some(userprofile) = home_dir() {
         // Regular Desktop
-        locations.push(PathBuf::from(&userprofile).join("Desktop"));
-        
+        locations.push(userprofile.join("Desktop"));
+
         // OneDrive Desktop
-        locations.push(PathBuf::from(&userprofile).join("OneDrive").join("Desktop"));
-        
+        locations.push(userprofile.join("OneDrive").join("Desktop"));
+
         // Start Menu
         if let Ok(appdata) = std::env::var("APPDATA") {
             locations.push(PathBuf::from(appdata).join("Microsoft\\Windows\\Start Menu\\Programs"));
@@ -107,6 +606,20 @@ fn get_shortcut_locations() -> Vec<PathBuf> {
     locations.into_iter().filter(|p| p.exists()).collect()
 }
 
+#[cfg(not(target_os = "windows"))]
+fn get_shortcut_locations() -> Vec<PathBuf> {
+    let mut locations = Vec::new();
+
+    if let Some(home) = home_dir() {
+        // .desktop launchers
+        locations.push(home.join(".local/share/applications"));
+        locations.push(home.join("Desktop"));
+    }
+
+    // Filter to only existing directories
+    locations.into_iter().filter(|p| p.exists()).collect()
+}
+
 #[tauri::command]
 fn scan_games(steamapps_path: String) -> Result<Vec<Game>, String> {
     let mut games = Vec::new();
@@ -148,10 +661,13 @@ fn scan_games(steamapps_path: String) -> Result<Vec<Game>, String> {
 fn parse_manifest(manifest_path: &Path, common_path: &Path) -> Result<Game, String> {
     let content = fs::read_to_string(manifest_path)
         .map_err(|e| format!("Failed to read manifest: {}", e))?;
-    
+
     let mut name = String::new();
     let mut app_id = String::new();
     let mut install_dir = String::new();
+    let mut state_flags: u32 = 0;
+    let mut bytes_downloaded: u64 = 0;
+    let mut bytes_to_download: u64 = 0;
 
     for line in content.lines() {
         if line.contains("\"appid\"") {
@@ -166,31 +682,116 @@ fn parse_manifest(manifest_path: &Path, common_path: &Path) -> Result<Game, Stri
             if let Some(val) = extract_value(line) {
                 install_dir = val;
             }
+        } else if line.contains("\"StateFlags\"") {
+            if let Some(val) = extract_value(line) {
+                state_flags = val.parse().unwrap_or(0);
+            }
+        } else if line.contains("\"BytesDownloaded\"") {
+            if let Some(val) = extract_value(line) {
+                bytes_downloaded = val.parse().unwrap_or(0);
+            }
+        } else if line.contains("\"BytesToDownload\"") {
+            if let Some(val) = extract_value(line) {
+                bytes_to_download = val.parse().unwrap_or(0);
+            }
         }
     }
 
+    if name.is_empty() || app_id.is_empty() {
+        return Err("Invalid manifest data".to_string());
+    }
+
     let game_path = common_path.join(&install_dir);
-    
-    if !name.is_empty() && !app_id.is_empty() && game_path.exists() {
-        Ok(Game {
-            name,
-            app_id,
-            path: install_dir,
-            status: "ready".to_string(),
-        })
-    } else {
-        Err("Invalid manifest data".to_string())
+    let (status, install_progress) =
+        manifest_status(state_flags, bytes_downloaded, bytes_to_download, game_path.exists());
+
+    Ok(Game {
+        name,
+        app_id,
+        path: install_dir,
+        status,
+        install_progress,
+    })
+}
+
+/// Bits of an appmanifest's `StateFlags` field that we care about.
+const STATE_FLAG_FULLY_INSTALLED: u32 = 4;
+const STATE_FLAG_UPDATE_RUNNING: u32 = 2;
+
+/// Interprets an appmanifest's `StateFlags` bitfield: 4 means fully installed,
+/// 2 means an update is actively downloading, and any other nonzero value
+/// means an update is queued/required but not currently running.
+fn manifest_status(
+    state_flags: u32,
+    bytes_downloaded: u64,
+    bytes_to_download: u64,
+    install_dir_exists: bool,
+) -> (String, Option<f32>) {
+    if !install_dir_exists {
+        return ("not_installed".to_string(), None);
+    }
+
+    if state_flags & STATE_FLAG_FULLY_INSTALLED != 0 {
+        return ("ready".to_string(), None);
+    }
+
+    if state_flags & STATE_FLAG_UPDATE_RUNNING != 0 {
+        let progress = if bytes_to_download > 0 {
+            Some((bytes_downloaded as f32 / bytes_to_download as f32) * 100.0)
+        } else {
+            Some(0.0)
+        };
+        return ("downloading".to_string(), progress);
     }
+
+    ("update_required".to_string(), None)
+}
+
+fn read_state_flags(manifest_path: &Path) -> Option<u32> {
+    let content = fs::read_to_string(manifest_path).ok()?;
+    for line in content.lines() {
+        if line.contains("\"StateFlags\"") {
+            return extract_value(line)?.parse().ok();
+        }
+    }
+    None
 }
 
 #[tauri::command]
-fn quick_fix_shortcuts() -> Result<Vec<ShortcutFix>, String> {
+fn install_game(steamapps_path: String, app_id: String, timeout_secs: u64) -> Result<String, String> {
+    open_steam_url(format!("steam://install/{}", app_id))?;
+
+    let libraries = get_steam_library_folders(&steamapps_path);
+    let manifest_filename = format!("appmanifest_{}.acf", app_id);
+    let poll_interval = std::time::Duration::from_secs(2);
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timeout_secs);
+
+    loop {
+        for library in &libraries {
+            let manifest_path = library.join(&manifest_filename);
+            if let Some(state_flags) = read_state_flags(&manifest_path) {
+                if state_flags & STATE_FLAG_FULLY_INSTALLED != 0 {
+                    return Ok("installed".to_string());
+                }
+            }
+        }
+
+        if std::time::Instant::now() >= deadline {
+            return Ok("timeout".to_string());
+        }
+
+        std::thread::sleep(poll_interval);
+    }
+}
+
+#[tauri::command]
+fn quick_fix_shortcuts(on_progress: tauri::ipc::Channel<ShortcutProgress>) -> Result<Vec<ShortcutFix>, String> {
     let mut fixes = Vec::new();
-    
+
     // Find Steam installation
     let steam_path = find_steam_install_directory()?;
     let icons_cache = steam_path.join("steam").join("games");
-    
+
     println!("Steam path: {:?}", steam_path);
     println!("Icons cache: {:?}", icons_cache);
 
@@ -204,45 +805,122 @@ fn quick_fix_shortcuts() -> Result<Vec<ShortcutFix>, String> {
     let locations = get_shortcut_locations();
     println!("Scanning {} locations", locations.len());
 
+    // Count candidates up front so progress can be a real percentage.
+    let candidates = collect_url_shortcuts(&locations);
+    let total = candidates.len();
+
+    let emit_progress = |label: Option<String>, done: usize, log_line: String, error: Option<String>| {
+        let _ = on_progress.send(ShortcutProgress {
+            label,
+            progress: done as f32 / total.max(1) as f32,
+            log_line,
+            error,
+        });
+    };
+
+    emit_progress(
+        None,
+        0,
+        format!("Found {} shortcut(s) to check across {} location(s)", total, locations.len()),
+        None,
+    );
+
+    for (index, (path, location_name)) in candidates.iter().enumerate() {
+        match process_shortcut(path, &icons_cache, location_name) {
+            Ok(fix) => {
+                println!("Fixed: {}", fix.name);
+                emit_progress(Some(fix.name.clone()), index + 1, format!("Fixed {}", fix.name), None);
+                fixes.push(fix);
+            }
+            Err(e) => {
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("Unknown")
+                    .to_string();
+                println!("Failed {}: {}", path.display(), e);
+                emit_progress(
+                    Some(name.clone()),
+                    index + 1,
+                    format!("Failed {}: {}", name, e),
+                    Some(e.clone()),
+                );
+                fixes.push(ShortcutFix {
+                    name,
+                    game_id: String::new(),
+                    icon_url: String::new(),
+                    location: location_name.clone(),
+                    success: false,
+                    error: Some(e),
+                });
+            }
+        }
+    }
+
+    emit_progress(None, total, format!("Finished: {} shortcut(s) processed", fixes.len()), None);
+
+    Ok(fixes)
+}
+
+/// Finds every candidate `.url` shortcut across the given locations, paired
+/// with the human-readable name of the location it was found in.
+fn collect_url_shortcuts(locations: &[PathBuf]) -> Vec<(PathBuf, String)> {
+    let mut candidates = Vec::new();
+
     for location in locations {
         println!("Scanning: {:?}", location);
-        
-        // Find all .url files recursively
-        if let Ok(entries) = fs::read_dir(&location) {
+        let location_name = location
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("Unknown")
+            .to_string();
+
+        if let Ok(entries) = fs::read_dir(location) {
             for entry in entries.flatten() {
                 let path = entry.path();
-                
-                if path.is_file() && path.extension().and_then(|s| s.to_str()).map(|s| s.eq_ignore_ascii_case("url")).unwrap_or(false) {
-                    let location_name = location.file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("Unknown");
-                    
-                    match process_shortcut(&path, &icons_cache, location_name) {
-                        Ok(fix) => {
-                            println!("Fixed: {}", fix.name);
-                            fixes.push(fix);
-                        }
-                        Err(e) => {
-                            println!("Failed {}: {}", path.display(), e);
-                            fixes.push(ShortcutFix {
-                                name: path.file_name()
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or("Unknown")
-                                    .to_string(),
-                                game_id: String::new(),
-                                icon_url: String::new(),
-                                location: location_name.to_string(),
-                                success: false,
-                                error: Some(e),
-                            });
-                        }
-                    }
+                if path.is_file()
+                    && path
+                        .extension()
+                        .and_then(|s| s.to_str())
+                        .map(|s| s.eq_ignore_ascii_case("url"))
+                        .unwrap_or(false)
+                {
+                    candidates.push((path, location_name.clone()));
                 }
             }
         }
     }
 
-    Ok(fixes)
+    candidates
+}
+
+fn download_icon_if_missing(icon_url: &str, cache_icon_path: &Path) -> Result<(), String> {
+    if cache_icon_path.exists() {
+        return Ok(());
+    }
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("HTTP client error: {}", e))?;
+
+    let response = client
+        .get(icon_url)
+        .send()
+        .map_err(|e| format!("Download failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    let bytes = response
+        .bytes()
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    fs::write(cache_icon_path, bytes)
+        .map_err(|e| format!("Failed to write icon: {}", e))?;
+
+    Ok(())
 }
 
 fn process_shortcut(file_path: &Path, icons_cache: &Path, location: &str) -> Result<ShortcutFix, String> {
@@ -291,30 +969,7 @@ fn process_shortcut(file_path: &Path, icons_cache: &Path, location: &str) -> Res
 
     // Download to central cache
     let cache_icon_path = icons_cache.join(&icon_filename);
-    
-    // Only download if not already cached
-    if !cache_icon_path.exists() {
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
-            .map_err(|e| format!("HTTP client error: {}", e))?;
-
-        let response = client
-            .get(&icon_url)
-            .send()
-            .map_err(|e| format!("Download failed: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("HTTP error: {}", response.status()));
-        }
-
-        let bytes = response
-            .bytes()
-            .map_err(|e| format!("Failed to read response: {}", e))?;
-
-        fs::write(&cache_icon_path, bytes)
-            .map_err(|e| format!("Failed to write icon: {}", e))?;
-    }
+    download_icon_if_missing(&icon_url, &cache_icon_path)?;
 
     Ok(ShortcutFix {
         name: file_path
@@ -330,6 +985,162 @@ fn process_shortcut(file_path: &Path, icons_cache: &Path, location: &str) -> Res
     })
 }
 
+/// Looks for an existing shortcut (any supported platform format) that already
+/// launches this app id, so `recreate_shortcuts` doesn't duplicate working shortcuts.
+fn find_existing_shortcut(game: &Game, locations: &[PathBuf]) -> Option<PathBuf> {
+    for location in locations {
+        if let Ok(entries) = fs::read_dir(location) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let is_shortcut = path
+                    .extension()
+                    .and_then(|s| s.to_str())
+                    .map(|ext| {
+                        ext.eq_ignore_ascii_case("url")
+                            || ext.eq_ignore_ascii_case("desktop")
+                            || ext.eq_ignore_ascii_case("lnk")
+                    })
+                    .unwrap_or(false);
+
+                if !path.is_file() || !is_shortcut {
+                    continue;
+                }
+
+                if let Ok(bytes) = fs::read(&path) {
+                    if contains_rungameid_marker(&bytes, &game.app_id) {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// `.url`/`.desktop` shortcuts are UTF-8 text, but `.lnk` files (MS-SHLLINK)
+/// are binary and store their target string as UTF-16LE, so check both
+/// encodings rather than assuming UTF-8 like `fs::read_to_string` would.
+fn contains_rungameid_marker(bytes: &[u8], app_id: &str) -> bool {
+    matches_rungameid(&String::from_utf8_lossy(bytes), app_id) || {
+        let utf16_units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        matches_rungameid(&String::from_utf16_lossy(&utf16_units), app_id)
+    }
+}
+
+/// Matches `rungameid/<app_id>` with a trailing non-digit or end-of-string
+/// boundary, so looking for app `570` can't be fooled by a shortcut for app
+/// `5700` ("rungameid/5700" containing "rungameid/570" as a plain substring).
+fn matches_rungameid(text: &str, app_id: &str) -> bool {
+    let pattern = format!(r"rungameid/{}(?:\D|$)", regex::escape(app_id));
+    Regex::new(&pattern).map(|re| re.is_match(text)).unwrap_or(false)
+}
+
+#[cfg(target_os = "windows")]
+fn create_platform_shortcut(name: &str, target_url: &str, icon_path: &Path, location: &Path) -> Result<PathBuf, String> {
+    let lnk_path = location.join(format!("{}.lnk", name));
+
+    let mut link = ShellLink::new(target_url).map_err(|e| format!("Failed to build shortcut: {}", e))?;
+    link.set_icon_location(Some(icon_path.to_string_lossy().to_string()));
+    link.create_lnk(&lnk_path)
+        .map_err(|e| format!("Failed to write shortcut: {}", e))?;
+
+    Ok(lnk_path)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn create_platform_shortcut(name: &str, target_url: &str, icon_path: &Path, location: &Path) -> Result<PathBuf, String> {
+    let desktop_path = location.join(format!("{}.desktop", name));
+
+    let contents = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec=steam {}\nIcon={}\nTerminal=false\n",
+        name,
+        target_url,
+        icon_path.display()
+    );
+
+    fs::write(&desktop_path, contents).map_err(|e| format!("Failed to write shortcut: {}", e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = fs::metadata(&desktop_path) {
+            let mut perms = metadata.permissions();
+            perms.set_mode(0o755);
+            let _ = fs::set_permissions(&desktop_path, perms);
+        }
+    }
+
+    Ok(desktop_path)
+}
+
+fn recreate_shortcut(game: &Game, icons_cache: &Path, location: &Path) -> Result<ShortcutFix, String> {
+    // Steam also serves the store icon under the app id alone for apps whose
+    // client icon hash matches their app id; good enough as a regeneration fallback.
+    let icon_filename = format!("{}.ico", game.app_id);
+    let icon_url = format!(
+        "https://cdn.cloudflare.steamstatic.com/steamcommunity/public/images/apps/{0}/{0}.ico",
+        game.app_id
+    );
+
+    let cache_icon_path = icons_cache.join(&icon_filename);
+    download_icon_if_missing(&icon_url, &cache_icon_path)?;
+
+    let target_url = format!("steam://rungameid/{}", game.app_id);
+    let shortcut_path = create_platform_shortcut(&game.name, &target_url, &cache_icon_path, location)?;
+
+    Ok(ShortcutFix {
+        name: game.name.clone(),
+        game_id: game.app_id.clone(),
+        icon_url,
+        location: shortcut_path.display().to_string(),
+        success: true,
+        error: None,
+    })
+}
+
+#[tauri::command]
+fn recreate_shortcuts(games: Vec<Game>) -> Result<Vec<ShortcutFix>, String> {
+    let steam_path = find_steam_install_directory()?;
+    let icons_cache = steam_path.join("steam").join("games");
+
+    if !icons_cache.exists() {
+        fs::create_dir_all(&icons_cache)
+            .map_err(|e| format!("Failed to create icons cache directory: {}", e))?;
+    }
+
+    let locations = get_shortcut_locations();
+    let target_location = locations
+        .first()
+        .cloned()
+        .ok_or("No shortcut location available to recreate into")?;
+
+    let mut fixes = Vec::new();
+
+    for game in games {
+        if find_existing_shortcut(&game, &locations).is_some() {
+            continue;
+        }
+
+        match recreate_shortcut(&game, &icons_cache, &target_location) {
+            Ok(fix) => fixes.push(fix),
+            Err(e) => fixes.push(ShortcutFix {
+                name: game.name.clone(),
+                game_id: game.app_id.clone(),
+                icon_url: String::new(),
+                location: target_location.display().to_string(),
+                success: false,
+                error: Some(e),
+            }),
+        }
+    }
+
+    Ok(fixes)
+}
+
 #[tauri::command]
 fn rename_game_folder(steamapps_path: String, game_path: String) -> Result<String, String> {
     let libraries = get_steam_library_folders(&steamapps_path);
@@ -397,6 +1208,76 @@ fn open_steam_url(url: String) -> Result<(), String> {
     Ok(())
 }
 
+// AppImage/Flatpak/Snap launchers inject LD_LIBRARY_PATH, GST_PLUGIN_PATH and a
+// rewritten PATH into our process; those leak into externally spawned GUI apps
+// and break their dynamic linking. Strip them before handing off to xdg-open.
+#[cfg(target_os = "linux")]
+fn sanitized_command(program: &str) -> Command {
+    let mut command = Command::new(program);
+    for var in ["LD_LIBRARY_PATH", "GST_PLUGIN_PATH", "GST_PLUGIN_SYSTEM_PATH", "GIO_EXTRA_MODULES"] {
+        command.env_remove(var);
+    }
+    command.env("PATH", "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin");
+    command
+}
+
+#[cfg(target_os = "linux")]
+fn reveal_via_file_manager_dbus(path: &Path) -> Result<(), String> {
+    let uri = format!("file://{}", path.display());
+    let connection = Connection::new_session().map_err(|e| format!("D-Bus connection failed: {}", e))?;
+    let proxy = connection.with_proxy(
+        "org.freedesktop.FileManager1",
+        "/org/freedesktop/FileManager1",
+        std::time::Duration::from_secs(5),
+    );
+
+    proxy
+        .method_call::<(), _, _, _>(
+            "org.freedesktop.FileManager1",
+            "ShowItems",
+            (vec![uri], String::new()),
+        )
+        .map_err(|e| format!("ShowItems failed: {}", e))
+}
+
+#[tauri::command]
+fn reveal_in_file_manager(path: String) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        // Rust's default Windows arg-quoting would wrap the whole
+        // "/select,<path>" string in quotes if path contains a space,
+        // which Explorer doesn't understand. It expects quotes only around
+        // the path itself, so build that exact raw argument ourselves.
+        use std::os::windows::process::CommandExt;
+        let select_arg = format!("/select,\"{}\"", path);
+        Command::new("explorer")
+            .raw_arg(&select_arg)
+            .spawn()
+            .map_err(|e| format!("Failed to open file manager: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .args(["-R", &path])
+            .spawn()
+            .map_err(|e| format!("Failed to open file manager: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if reveal_via_file_manager_dbus(Path::new(&path)).is_err() {
+            let parent = Path::new(&path).parent().unwrap_or_else(|| Path::new(&path));
+            sanitized_command("xdg-open")
+                .arg(parent)
+                .spawn()
+                .map_err(|e| format!("Failed to open file manager: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 fn cleanup_temp_folders(steamapps_path: String) -> Result<Vec<String>, String> {
     let mut cleaned = Vec::new();
@@ -438,6 +1319,13 @@ pub fn run() {
             open_steam_url,
             cleanup_temp_folders,
             quick_fix_shortcuts,
+            list_steam_accounts,
+            add_nonsteam_shortcut,
+            list_nonsteam_shortcuts,
+            remove_nonsteam_shortcut,
+            recreate_shortcuts,
+            install_game,
+            reveal_in_file_manager,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");